@@ -0,0 +1,238 @@
+use crate::{
+    api::Namespace,
+    helpers::{self, CallFuture},
+    types::{BlockNumber, CallRequest, GethDebugTracingOptions, GethTrace, TxTraceResult, H256},
+    Transport,
+};
+
+/// `Debug` namespace
+#[derive(Debug, Clone)]
+pub struct Debug<T> {
+    transport: T,
+}
+
+impl<T: Transport> Namespace<T> for Debug<T> {
+    fn new(transport: T) -> Self
+    where
+        Self: Sized,
+    {
+        Debug { transport }
+    }
+
+    fn transport(&self) -> &T {
+        &self.transport
+    }
+}
+
+impl<T: Transport> Debug<T> {
+    /// Traces a single transaction, re-executing it against the state of the block it was mined in
+    pub fn trace_transaction(
+        &self,
+        hash: H256,
+        options: Option<GethDebugTracingOptions>,
+    ) -> CallFuture<GethTrace, T::Out> {
+        let hash = helpers::serialize(&hash);
+        let options = helpers::serialize(&options.unwrap_or_default());
+        CallFuture::new(self.transport.execute("debug_traceTransaction", vec![hash, options]))
+    }
+
+    /// Traces a call, executing it against the state of the given block without creating a transaction
+    pub fn trace_call(
+        &self,
+        req: CallRequest,
+        block: Option<BlockNumber>,
+        options: Option<GethDebugTracingOptions>,
+    ) -> CallFuture<GethTrace, T::Out> {
+        let req = helpers::serialize(&req);
+        let block = helpers::serialize(&block.unwrap_or(BlockNumber::Latest));
+        let options = helpers::serialize(&options.unwrap_or_default());
+        CallFuture::new(self.transport.execute("debug_traceCall", vec![req, block, options]))
+    }
+
+    /// Traces every transaction in a block, identified by number
+    pub fn trace_block_by_number(
+        &self,
+        block: BlockNumber,
+        options: Option<GethDebugTracingOptions>,
+    ) -> CallFuture<Vec<TxTraceResult>, T::Out> {
+        let block = helpers::serialize(&block);
+        let options = helpers::serialize(&options.unwrap_or_default());
+        CallFuture::new(
+            self.transport
+                .execute("debug_traceBlockByNumber", vec![block, options]),
+        )
+    }
+
+    /// Traces every transaction in a block, identified by hash
+    pub fn trace_block_by_hash(
+        &self,
+        hash: H256,
+        options: Option<GethDebugTracingOptions>,
+    ) -> CallFuture<Vec<TxTraceResult>, T::Out> {
+        let hash = helpers::serialize(&hash);
+        let options = helpers::serialize(&options.unwrap_or_default());
+        CallFuture::new(self.transport.execute("debug_traceBlockByHash", vec![hash, options]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Debug;
+    use crate::{
+        api::Namespace,
+        types::{Address, BlockNumber, CallRequest, GethTrace, TxTraceResult, H256},
+    };
+
+    const EXAMPLE_DEFAULT_FRAME: &str = r#"
+    {
+        "gas": "0x5208",
+        "failed": false,
+        "returnValue": "0x",
+        "structLogs": [
+            {
+                "pc": 0,
+                "op": "PUSH1",
+                "gas": 21000,
+                "gasCost": 3,
+                "depth": 1,
+                "stack": []
+            }
+        ]
+    }
+    "#;
+
+    const EXAMPLE_CALL_FRAME: &str = r#"
+    {
+        "type": "CALL",
+        "from": "0x0000000000000000000000000000000000000000",
+        "to": "0x0000000000000000000000000000000000000123",
+        "value": "0x0",
+        "gas": "0x5208",
+        "gasUsed": "0x5208",
+        "input": "0x",
+        "output": "0x",
+        "calls": [
+            {
+                "type": "STATICCALL",
+                "from": "0x0000000000000000000000000000000000000123",
+                "to": "0x0000000000000000000000000000000000000456",
+                "gas": "0x100",
+                "gasUsed": "0x50",
+                "input": "0x",
+                "output": "0x"
+            }
+        ]
+    }
+    "#;
+
+    const EXAMPLE_PRESTATE_FRAME: &str = r#"
+    {
+        "0x0000000000000000000000000000000000000123": {
+            "balance": "0x1",
+            "nonce": 1,
+            "code": "0x",
+            "storage": {}
+        }
+    }
+    "#;
+
+    const EXAMPLE_FOURBYTE_FRAME: &str = r#"
+    {
+        "0xa9059cbb-68": 1
+    }
+    "#;
+
+    const EXAMPLE_UNKNOWN_FRAME: &str = r#"
+    ["custom JS tracer output", 1, null]
+    "#;
+
+    const EXAMPLE_TX_TRACE_RESULTS: &str = r#"
+    [
+        {
+            "txHash": "0x0000000000000000000000000000000000000000000000000000000000000123",
+            "result": {
+                "gas": "0x5208",
+                "failed": false,
+                "returnValue": "0x",
+                "structLogs": []
+            }
+        },
+        {
+            "txHash": "0x0000000000000000000000000000000000000000000000000000000000000124",
+            "error": "execution reverted"
+        }
+    ]
+    "#;
+
+    #[test]
+    fn should_decode_a_call_tracer_frame() {
+        let trace: GethTrace = ::serde_json::from_str(EXAMPLE_CALL_FRAME).unwrap();
+        assert!(matches!(trace, GethTrace::CallTracer(_)));
+    }
+
+    #[test]
+    fn should_decode_a_prestate_tracer_frame() {
+        let trace: GethTrace = ::serde_json::from_str(EXAMPLE_PRESTATE_FRAME).unwrap();
+        assert!(matches!(trace, GethTrace::PrestateTracer(_)));
+    }
+
+    #[test]
+    fn should_decode_a_fourbyte_tracer_frame() {
+        let trace: GethTrace = ::serde_json::from_str(EXAMPLE_FOURBYTE_FRAME).unwrap();
+        assert!(matches!(trace, GethTrace::FourByteTracer(_)));
+    }
+
+    #[test]
+    fn should_decode_an_unknown_tracer_frame() {
+        let trace: GethTrace = ::serde_json::from_str(EXAMPLE_UNKNOWN_FRAME).unwrap();
+        assert!(matches!(trace, GethTrace::Unknown(_)));
+    }
+
+    #[test]
+    fn should_decode_tx_trace_results_with_result_and_error() {
+        let results: Vec<TxTraceResult> = ::serde_json::from_str(EXAMPLE_TX_TRACE_RESULTS).unwrap();
+        assert_eq!(results.len(), 2);
+        assert!(results[0].result.is_some());
+        assert!(results[0].error.is_none());
+        assert!(results[1].result.is_none());
+        assert_eq!(results[1].error.as_deref(), Some("execution reverted"));
+    }
+
+    rpc_test!(
+    Debug:trace_transaction, "0000000000000000000000000000000000000000000000000000000000000123".parse::<H256>().unwrap(), None
+    =>
+    "debug_traceTransaction", vec![r#""0x0000000000000000000000000000000000000000000000000000000000000123""#, r#"{}"#];
+    ::serde_json::from_str(EXAMPLE_DEFAULT_FRAME).unwrap()
+    => ::serde_json::from_str::<GethTrace>(EXAMPLE_DEFAULT_FRAME).unwrap()
+    );
+
+    rpc_test!(
+    Debug:trace_call, CallRequest {
+    from: None, to: Some(Address::from_low_u64_be(0x123)),
+    gas: None, gas_price: None,
+    value: Some(0x1.into()), data: None,
+    transaction_type: None, access_list: None,
+    max_fee_per_gas: None, max_priority_fee_per_gas: None,
+    }, None, None
+    =>
+    "debug_traceCall", vec![r#"{"to":"0x0000000000000000000000000000000000000123","value":"0x1"}"#, r#""latest""#, r#"{}"#];
+    ::serde_json::from_str(EXAMPLE_DEFAULT_FRAME).unwrap()
+    => ::serde_json::from_str::<GethTrace>(EXAMPLE_DEFAULT_FRAME).unwrap()
+    );
+
+    rpc_test!(
+    Debug:trace_block_by_number, BlockNumber::Latest, None
+    =>
+    "debug_traceBlockByNumber", vec![r#""latest""#, r#"{}"#];
+    ::serde_json::from_str(EXAMPLE_TX_TRACE_RESULTS).unwrap()
+    => ::serde_json::from_str::<Vec<TxTraceResult>>(EXAMPLE_TX_TRACE_RESULTS).unwrap()
+    );
+
+    rpc_test!(
+    Debug:trace_block_by_hash, "0000000000000000000000000000000000000000000000000000000000000123".parse::<H256>().unwrap(), None
+    =>
+    "debug_traceBlockByHash", vec![r#""0x0000000000000000000000000000000000000000000000000000000000000123""#, r#"{}"#];
+    ::serde_json::from_str(EXAMPLE_TX_TRACE_RESULTS).unwrap()
+    => ::serde_json::from_str::<Vec<TxTraceResult>>(EXAMPLE_TX_TRACE_RESULTS).unwrap()
+    );
+}
@@ -4,7 +4,6 @@ use crate::{
     types::{BlockId, BlockNumber, BlockTrace, Bytes, CallRequest, Index, Trace, TraceFilter, TraceType, H256},
     Transport,
 };
-use std::collections::HashMap;
 
 /// `Trace` namespace
 #[derive(Debug, Clone)]
@@ -87,10 +86,7 @@ impl<T: Transport> Traces<T> {
     /// Returns traces created at given block
     pub fn block(&self, block: BlockNumber) -> CallFuture<Vec<Trace>, T::Out> {
         let block = helpers::serialize(&block);
-        let mut map = HashMap::new();
-        map.insert("tracer".to_string(), "callTracer".to_string());
-        let tracer = helpers::serialize(&map);
-        CallFuture::new(self.transport.execute("debug_traceBlockByNumber", vec![block, tracer]))
+        CallFuture::new(self.transport.execute("trace_block", vec![block]))
     }
 
     /// Return traces matching the given filter
@@ -120,7 +116,10 @@ mod tests {
     use super::Traces;
     use crate::{
         api::Namespace,
-        types::{Address, BlockNumber, BlockTrace, CallRequest, Trace, TraceFilterBuilder, TraceType, H256},
+        types::{
+            Action, Address, BlockNumber, BlockTrace, CallRequest, CallType, Diff, RewardType, Res, Trace,
+            TraceFilterBuilder, TraceOutput, TraceType, H256,
+        },
     };
     use hex_literal::hex;
 
@@ -151,6 +150,82 @@ mod tests {
     }
     "#;
 
+    const EXAMPLE_BLOCKTRACE_WITH_VM_AND_STATE_DIFF: &str = r#"
+    {
+        "output": "0x",
+        "stateDiff": {
+            "0xaa7b131dc60b80d3cf5e59b5a21a666aa039c951": {
+                "balance": {"*": {"from": "0x1", "to": "0x0"}},
+                "nonce": {"*": {"from": "0x0", "to": "0x1"}},
+                "code": "=",
+                "storage": {
+                    "0x0000000000000000000000000000000000000000000000000000000000000001": {
+                        "+": "0x0000000000000000000000000000000000000000000000000000000000000002"
+                    }
+                }
+            }
+        },
+        "trace": [
+            {
+                "action": {
+                    "callType": "call",
+                    "from": "0x0000000000000000000000000000000000000000",
+                    "gas": "0x1dcd12f8",
+                    "input": "0x",
+                    "to": "0x0000000000000000000000000000000000000123",
+                    "value": "0x1"
+                },
+                "result": {
+                    "gasUsed": "0x0",
+                    "output": "0x"
+                },
+                "subtraces": 0,
+                "traceAddress": [],
+                "type": "call"
+            }
+        ],
+        "vmTrace": {
+            "code": "0x600160020a",
+            "ops": [
+                {
+                    "pc": 0,
+                    "cost": 3,
+                    "ex": {
+                        "used": 79997,
+                        "push": ["0x1"],
+                        "mem": null,
+                        "store": null
+                    },
+                    "sub": {
+                        "code": "0x00",
+                        "ops": []
+                    }
+                }
+            ]
+        }
+    }
+    "#;
+
+    #[test]
+    fn should_decode_a_populated_vm_trace_and_state_diff() {
+        let block_trace: BlockTrace = ::serde_json::from_str(EXAMPLE_BLOCKTRACE_WITH_VM_AND_STATE_DIFF).unwrap();
+
+        let vm_trace = block_trace.vm_trace.expect("vmTrace should be populated");
+        assert_eq!(vm_trace.ops.len(), 1);
+        let op = &vm_trace.ops[0];
+        let sub = op.sub.as_ref().expect("nested vmTrace for the sub-call");
+        assert!(sub.ops.is_empty());
+
+        let state_diff = block_trace.state_diff.expect("stateDiff should be populated");
+        let account = state_diff
+            .get(&"0xaa7b131dc60b80d3cf5e59b5a21a666aa039c951".parse::<Address>().unwrap())
+            .expect("account should be present in the state diff");
+        assert_eq!(account.code, Diff::Same);
+        assert!(matches!(account.balance, Diff::Changed { .. }));
+        let storage_entry = account.storage.values().next().expect("storage entry");
+        assert!(matches!(storage_entry, Diff::Born(_)));
+    }
+
     const EXAMPLE_BLOCKTRACES: &str = r#"
 	[{
         "output": "0x",
@@ -229,6 +304,117 @@ mod tests {
       }
     "#;
 
+    const EXAMPLE_TRACE_ERROR: &str = r#"
+      {
+          "action": {
+              "callType": "call",
+              "from": "0xaa7b131dc60b80d3cf5e59b5a21a666aa039c951",
+              "gas": "0x0",
+              "input": "0x",
+              "to": "0xd40aba8166a212d6892125f079c33e6f5ca19814",
+              "value": "0x0"
+          },
+          "error": "Reverted",
+          "subtraces": 0,
+          "traceAddress": [0],
+          "type": "call"
+      }
+    "#;
+
+    #[test]
+    fn should_decode_a_reverted_call_as_trace_output_error() {
+        let trace: Trace = ::serde_json::from_str(EXAMPLE_TRACE_ERROR).unwrap();
+        assert_eq!(trace.result, Res::Call(TraceOutput::Error("Reverted".into())));
+    }
+
+    const EXAMPLE_TRACE_CREATE2: &str = r#"
+      {
+          "action": {
+              "from": "0xaa7b131dc60b80d3cf5e59b5a21a666aa039c951",
+              "gas": "0x0",
+              "init": "0x600160020a",
+              "value": "0x0",
+              "salt": "0x0000000000000000000000000000000000000000000000000000000000000001"
+          },
+          "result": {
+              "gasUsed": "0x0",
+              "address": "0xd40aba8166a212d6892125f079c33e6f5ca19814",
+              "code": "0x"
+          },
+          "subtraces": 0,
+          "traceAddress": [],
+          "type": "create"
+      }
+    "#;
+
+    #[test]
+    fn should_decode_a_create2_salt() {
+        let trace: Trace = ::serde_json::from_str(EXAMPLE_TRACE_CREATE2).unwrap();
+        match trace.action {
+            Action::Create(create) => assert_eq!(
+                create.salt,
+                Some("0000000000000000000000000000000000000000000000000000000000000001".parse::<H256>().unwrap())
+            ),
+            other => panic!("expected a create action, got {:?}", other),
+        }
+    }
+
+    const EXAMPLE_TRACE_SUICIDE: &str = r#"
+      {
+          "action": {
+              "address": "0xaa7b131dc60b80d3cf5e59b5a21a666aa039c951",
+              "refundAddress": "0xd40aba8166a212d6892125f079c33e6f5ca19814",
+              "balance": "0x1"
+          },
+          "subtraces": 0,
+          "traceAddress": [],
+          "type": "suicide"
+      }
+    "#;
+
+    #[test]
+    fn should_decode_a_suicide_action() {
+        let trace: Trace = ::serde_json::from_str(EXAMPLE_TRACE_SUICIDE).unwrap();
+        assert_eq!(trace.result, Res::None);
+        assert!(matches!(trace.action, Action::Suicide(_)));
+    }
+
+    const EXAMPLE_TRACE_REWARD: &str = r#"
+      {
+          "action": {
+              "author": "0xaa7b131dc60b80d3cf5e59b5a21a666aa039c951",
+              "value": "0x4563918244f40000",
+              "rewardType": "block"
+          },
+          "subtraces": 0,
+          "traceAddress": [],
+          "type": "reward"
+      }
+    "#;
+
+    #[test]
+    fn should_decode_a_reward_action() {
+        let trace: Trace = ::serde_json::from_str(EXAMPLE_TRACE_REWARD).unwrap();
+        assert_eq!(trace.result, Res::None);
+        match trace.action {
+            Action::Reward(reward) => assert_eq!(reward.reward_type, RewardType::Block),
+            other => panic!("expected a reward action, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn should_decode_all_call_types() {
+        for (json_type, expected) in [
+            ("call", CallType::Call),
+            ("callcode", CallType::CallCode),
+            ("delegatecall", CallType::DelegateCall),
+            ("staticcall", CallType::StaticCall),
+        ] {
+            let call_type: CallType = ::serde_json::from_str(&format!("\"{}\"", json_type)).unwrap();
+            assert_eq!(call_type, expected);
+        }
+    }
+
     rpc_test!(
     Traces:call, CallRequest {
     from: None, to: Some(Address::from_low_u64_be(0x123)),
@@ -281,6 +467,14 @@ mod tests {
     => ::serde_json::from_str::<Vec<Trace>>(EXAMPLE_TRACE_ARR).unwrap()
     );
 
+    rpc_test!(
+    Traces:filter, TraceFilterBuilder::default().after(10).count(50).build()
+    =>
+    "trace_filter", vec![r#"{"after":10,"count":50}"#];
+    ::serde_json::from_str(EXAMPLE_TRACE_ARR).unwrap()
+    => ::serde_json::from_str::<Vec<Trace>>(EXAMPLE_TRACE_ARR).unwrap()
+    );
+
     rpc_test!(
     Traces:get, "0000000000000000000000000000000000000000000000000000000000000123".parse::<H256>().unwrap(), vec![0.into()]
     =>
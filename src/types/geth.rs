@@ -0,0 +1,208 @@
+//! Types for the Geth `debug` tracing API (`debug_traceTransaction` and friends).
+use crate::types::{Address, Bytes, H256, U256};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// One of the tracers built into Geth, or a custom JS tracer.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum GethDebugBuiltInTracerType {
+    /// Records the call frame tree of a transaction.
+    CallTracer,
+    /// Records the state touched by a transaction, before and/or after execution.
+    PrestateTracer,
+    /// Records the function selectors (first four bytes of the calldata) hit during execution.
+    FourByteTracer,
+    /// A tracer supplied as raw JavaScript source, evaluated by Geth's JS tracing engine.
+    JsTracer(String),
+}
+
+impl Serialize for GethDebugBuiltInTracerType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GethDebugBuiltInTracerType::CallTracer => serializer.serialize_str("callTracer"),
+            GethDebugBuiltInTracerType::PrestateTracer => serializer.serialize_str("prestateTracer"),
+            GethDebugBuiltInTracerType::FourByteTracer => serializer.serialize_str("4byteTracer"),
+            GethDebugBuiltInTracerType::JsTracer(code) => serializer.serialize_str(code),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for GethDebugBuiltInTracerType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(match s.as_str() {
+            "callTracer" => GethDebugBuiltInTracerType::CallTracer,
+            "prestateTracer" => GethDebugBuiltInTracerType::PrestateTracer,
+            "4byteTracer" => GethDebugBuiltInTracerType::FourByteTracer,
+            _ => GethDebugBuiltInTracerType::JsTracer(s),
+        })
+    }
+}
+
+/// Options for a `debug_trace*` call: the tracer to run, its `tracerConfig`, a timeout, plus the
+/// struct-logger toggles that apply when no tracer (or the default struct logger) is selected.
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct GethDebugTracingOptions {
+    /// Name of a built-in tracer, or raw JS tracer source. Defaults to the struct logger.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracer: Option<GethDebugBuiltInTracerType>,
+    /// Tracer-specific configuration, e.g. `{"onlyTopCall": true}` for `callTracer`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tracer_config: Option<serde_json::Value>,
+    /// Overall timeout for the tracing call, e.g. `"5s"`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout: Option<String>,
+    /// Setting this to `true` disables storage capture in struct-log mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_storage: Option<bool>,
+    /// Setting this to `true` disables stack capture in struct-log mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disable_stack: Option<bool>,
+    /// Setting this to `true` enables memory capture in struct-log mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_memory: Option<bool>,
+    /// Setting this to `true` enables the return data capture in struct-log mode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_return_data: Option<bool>,
+}
+
+/// A single step of the default struct logger.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StructLog {
+    /// Program counter.
+    pub pc: u64,
+    /// Opcode mnemonic.
+    pub op: String,
+    /// Remaining gas before executing this instruction.
+    pub gas: u64,
+    /// Gas cost of this instruction.
+    #[serde(rename = "gasCost")]
+    pub gas_cost: u64,
+    /// Call depth.
+    pub depth: u64,
+    /// Error message, if execution failed at this step.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// EVM stack contents, if enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stack: Option<Vec<U256>>,
+    /// EVM memory contents, if enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memory: Option<Vec<String>>,
+    /// Storage slots touched so far, if enabled.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<BTreeMap<String, String>>,
+}
+
+/// Trace produced when no tracer (or the default struct logger) is selected.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DefaultFrame {
+    /// Total gas used by the call.
+    pub gas: U256,
+    /// Whether the call failed.
+    pub failed: bool,
+    /// Return value of the call.
+    pub return_value: Bytes,
+    /// One entry per executed instruction.
+    pub struct_logs: Vec<StructLog>,
+}
+
+/// Single frame of the call tree produced by `callTracer`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct CallFrame {
+    /// Kind of call (`CALL`, `STATICCALL`, `DELEGATECALL`, `CREATE`, ...).
+    #[serde(rename = "type")]
+    pub typ: String,
+    /// Caller address.
+    pub from: Address,
+    /// Callee address. Absent for contract creations that revert before deployment.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<Address>,
+    /// Value transferred.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<U256>,
+    /// Gas supplied to the call.
+    pub gas: U256,
+    /// Gas used by the call.
+    pub gas_used: U256,
+    /// Call input data.
+    pub input: Bytes,
+    /// Call return data, if the call did not error.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output: Option<Bytes>,
+    /// Error message, if the call reverted or ran out of gas.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    /// Nested calls made by this call.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub calls: Option<Vec<CallFrame>>,
+}
+
+/// Pre/post transaction state of a single account, as reported by `prestateTracer`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct PrestateAccount {
+    /// Balance of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub balance: Option<U256>,
+    /// Nonce of the account.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nonce: Option<u64>,
+    /// Code of the account, if it is a contract.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub code: Option<Bytes>,
+    /// Storage slots touched, keyed by slot.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage: Option<BTreeMap<H256, H256>>,
+}
+
+/// Output of `prestateTracer`: the state of every account touched by the transaction.
+pub type PrestateFrame = BTreeMap<Address, PrestateAccount>;
+
+/// Output of `4byteTracer`: a count of calls per `<4-byte selector>-<calldata size>` key.
+pub type FourByteFrame = BTreeMap<String, u64>;
+
+/// Result of a `debug_trace*` call.
+///
+/// Decoding goes by the shape of the JSON, not by the tracer that was requested: the variants are
+/// tried in the order below and the first one that fits is taken. Output that fits none of the
+/// typed variants falls back to `Unknown` so callers can still get at the raw JSON. Output of a
+/// custom JS tracer that happens to fit a typed shape decodes into that variant, and an empty
+/// object decodes as `PrestateTracer`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum GethTrace {
+    /// Output of the default struct logger.
+    Default(DefaultFrame),
+    /// Output of `callTracer`.
+    CallTracer(CallFrame),
+    /// Output of `prestateTracer`.
+    PrestateTracer(PrestateFrame),
+    /// Output of `4byteTracer`.
+    FourByteTracer(FourByteFrame),
+    /// Output of a custom JS tracer, or any shape not covered by the variants above.
+    Unknown(serde_json::Value),
+}
+
+/// One entry of the array returned by `debug_traceBlockByNumber`/`debug_traceBlockByHash`: the
+/// trace of a single transaction in the block, or the error that aborted it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct TxTraceResult {
+    /// Hash of the traced transaction.
+    pub tx_hash: H256,
+    /// Trace output, if the transaction was traced successfully.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub result: Option<GethTrace>,
+    /// Error message, if tracing this transaction failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -0,0 +1,434 @@
+//! Types for the Parity/OpenEthereum `trace_*` family of RPC methods.
+use crate::types::{Address, BlockNumber, Bytes, H256, U256};
+use serde::{
+    de::{Error as DeError, Deserializer},
+    Deserialize, Serialize,
+};
+use std::collections::BTreeMap;
+
+/// Description of the type of trace to make
+#[derive(Debug, Serialize, Clone, PartialEq, Eq, Hash)]
+#[serde(rename_all = "camelCase")]
+pub enum TraceType {
+    /// Trace
+    Trace,
+    /// State Diff
+    StateDiff,
+    /// VM Trace
+    VmTrace,
+}
+
+/// The kind of call a `CallAction` represents.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CallType {
+    /// `CALL`
+    Call,
+    /// `CALLCODE`
+    CallCode,
+    /// `DELEGATECALL`
+    DelegateCall,
+    /// `STATICCALL`
+    StaticCall,
+}
+
+/// Call action, i.e. `CALL`/`CALLCODE`/`DELEGATECALL`/`STATICCALL`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallAction {
+    /// Type of the call
+    pub call_type: CallType,
+    /// Sender
+    pub from: Address,
+    /// Gas
+    pub gas: U256,
+    /// Input data
+    pub input: Bytes,
+    /// Receiver
+    pub to: Address,
+    /// Transferred value
+    pub value: U256,
+}
+
+/// Create action, i.e. contract deployment via `CREATE` or `CREATE2`.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateAction {
+    /// Sender
+    pub from: Address,
+    /// Gas
+    pub gas: U256,
+    /// Init code
+    pub init: Bytes,
+    /// Transferred value
+    pub value: U256,
+    /// Salt used to derive the contract address, if this was a `CREATE2` deployment.
+    ///
+    /// OpenEthereum/Parity's own `trace_*` responses do not currently emit this field, so it
+    /// decodes to `None` against those nodes even for `CREATE2` deployments; it is modeled here
+    /// for clients (and future node versions) that do report it.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub salt: Option<H256>,
+}
+
+/// A self-destruct/`SUICIDE` action.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuicideAction {
+    /// Address of the contract that self-destructed
+    pub address: Address,
+    /// Address the remaining balance was sent to
+    pub refund_address: Address,
+    /// Balance that was transferred to `refund_address`
+    pub balance: U256,
+}
+
+/// Whether a block reward action rewards the block author or an included uncle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RewardType {
+    /// Block reward
+    Block,
+    /// Uncle reward
+    Uncle,
+}
+
+/// A block/uncle reward action, emitted by `trace_block` in addition to transaction traces.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RewardAction {
+    /// Address of the beneficiary
+    pub author: Address,
+    /// Reward amount
+    pub value: U256,
+    /// Whether this rewards the block or an uncle
+    pub reward_type: RewardType,
+}
+
+/// The result of a successful call action.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CallResult {
+    /// Gas used
+    pub gas_used: U256,
+    /// Output data
+    pub output: Bytes,
+}
+
+/// The result of a successful create action.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateResult {
+    /// Gas used
+    pub gas_used: U256,
+    /// Address of the created contract
+    pub address: Address,
+    /// Contract code
+    pub code: Bytes,
+}
+
+/// Action
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Action {
+    /// Call
+    Call(CallAction),
+    /// Create
+    Create(CreateAction),
+    /// Self-destruct
+    Suicide(SuicideAction),
+    /// Block/uncle reward
+    Reward(RewardAction),
+}
+
+/// Outcome of a traced call or create: either the successful output, or the error message
+/// reported in place of `result` (e.g. `"Reverted"`, `"Out of gas"`) for a failed sub-call.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TraceOutput<T> {
+    /// The call/create completed successfully
+    Success(T),
+    /// The call/create failed with this error
+    Error(String),
+}
+
+/// Action result
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Res {
+    /// Call
+    Call(TraceOutput<CallResult>),
+    /// Create
+    Create(TraceOutput<CreateResult>),
+    /// No result, e.g. for `Suicide`/`Reward` actions
+    None,
+}
+
+/// Trace
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    /// Action
+    pub action: Action,
+    /// Result
+    pub result: Res,
+    /// Subtraces
+    pub subtraces: usize,
+    /// Trace address
+    pub trace_address: Vec<usize>,
+    /// Transaction hash
+    pub transaction_hash: Option<H256>,
+    /// Transaction position
+    pub transaction_position: Option<usize>,
+    /// Block hash
+    pub block_hash: Option<H256>,
+    /// Block number
+    pub block_number: Option<u64>,
+}
+
+/// Raw, `type`-tagged shape that `Trace` is actually encoded as on the wire: `action` and
+/// `result` are untyped until the sibling `type` field tells us which variant to parse them as.
+#[derive(Debug, Deserialize)]
+struct RawTrace {
+    action: serde_json::Value,
+    #[serde(default)]
+    result: Option<serde_json::Value>,
+    #[serde(default)]
+    error: Option<String>,
+    subtraces: usize,
+    #[serde(rename = "traceAddress")]
+    trace_address: Vec<usize>,
+    #[serde(rename = "transactionHash")]
+    transaction_hash: Option<H256>,
+    #[serde(rename = "transactionPosition")]
+    transaction_position: Option<usize>,
+    #[serde(rename = "blockHash")]
+    block_hash: Option<H256>,
+    #[serde(rename = "blockNumber")]
+    block_number: Option<u64>,
+    #[serde(rename = "type")]
+    trace_type: String,
+}
+
+impl<'de> Deserialize<'de> for Trace {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawTrace::deserialize(deserializer)?;
+        let require_result = |result: Option<serde_json::Value>| {
+            result.ok_or_else(|| DeError::custom("trace has neither `result` nor `error`"))
+        };
+        let (action, result) = match raw.trace_type.as_str() {
+            "call" => (
+                Action::Call(serde_json::from_value(raw.action).map_err(DeError::custom)?),
+                Res::Call(match raw.error {
+                    Some(error) => TraceOutput::Error(error),
+                    None => TraceOutput::Success(
+                        serde_json::from_value(require_result(raw.result)?).map_err(DeError::custom)?,
+                    ),
+                }),
+            ),
+            "create" => (
+                Action::Create(serde_json::from_value(raw.action).map_err(DeError::custom)?),
+                Res::Create(match raw.error {
+                    Some(error) => TraceOutput::Error(error),
+                    None => TraceOutput::Success(
+                        serde_json::from_value(require_result(raw.result)?).map_err(DeError::custom)?,
+                    ),
+                }),
+            ),
+            "suicide" => (
+                Action::Suicide(serde_json::from_value(raw.action).map_err(DeError::custom)?),
+                Res::None,
+            ),
+            "reward" => (
+                Action::Reward(serde_json::from_value(raw.action).map_err(DeError::custom)?),
+                Res::None,
+            ),
+            other => return Err(DeError::custom(format!("unknown trace type `{}`", other))),
+        };
+        Ok(Trace {
+            action,
+            result,
+            subtraces: raw.subtraces,
+            trace_address: raw.trace_address,
+            transaction_hash: raw.transaction_hash,
+            transaction_position: raw.transaction_position,
+            block_hash: raw.block_hash,
+            block_number: raw.block_number,
+        })
+    }
+}
+
+/// A diffed value.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum Diff<T> {
+    /// Unchanged
+    #[serde(rename = "=")]
+    Same,
+    /// Brought into existence
+    #[serde(rename = "+")]
+    Born(T),
+    /// Removed
+    #[serde(rename = "-")]
+    Died(T),
+    /// Changed
+    #[serde(rename = "*")]
+    Changed {
+        /// Value before the transaction
+        from: T,
+        /// Value after the transaction
+        to: T,
+    },
+}
+
+/// Account diff, i.e. the before/after state of a single account touched by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct AccountDiff {
+    /// Balance
+    pub balance: Diff<U256>,
+    /// Nonce
+    pub nonce: Diff<U256>,
+    /// Code
+    pub code: Diff<Bytes>,
+    /// Storage
+    pub storage: BTreeMap<H256, Diff<H256>>,
+}
+
+/// State diff, keyed by the address of each touched account.
+pub type StateDiff = BTreeMap<Address, AccountDiff>;
+
+/// A change to memory caused by a VM operation.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct MemoryDiff {
+    /// Offset into memory the change begins at
+    pub off: usize,
+    /// The changed data
+    pub data: Bytes,
+}
+
+/// A change to the storage of a contract.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StorageDiff {
+    /// Storage slot
+    pub key: U256,
+    /// Slot value after the operation
+    pub val: U256,
+}
+
+/// The result of a successfully executed VM operation.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VMExecutedOperation {
+    /// Gas used
+    pub used: u64,
+    /// Values pushed onto the stack
+    pub push: Vec<U256>,
+    /// If the operation modified memory, the change that occurred
+    pub mem: Option<MemoryDiff>,
+    /// If the operation modified storage, the change that occurred
+    pub store: Option<StorageDiff>,
+}
+
+/// A single VM operation.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VMOperation {
+    /// Program counter
+    pub pc: usize,
+    /// Gas cost
+    pub cost: u64,
+    /// Result of executing the operation, `None` if the operation errored or ran out of gas
+    pub ex: Option<VMExecutedOperation>,
+    /// Subordinate trace of the call/create if the instruction is one, `None` otherwise
+    pub sub: Option<Box<VMTrace>>,
+}
+
+/// A full trace of the VM's state throughout the execution of a transaction.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct VMTrace {
+    /// The code to be executed
+    pub code: Bytes,
+    /// The operations executed
+    pub ops: Vec<VMOperation>,
+}
+
+/// Trace
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct BlockTrace {
+    /// Output
+    pub output: Bytes,
+    /// State diff
+    #[serde(rename = "stateDiff")]
+    pub state_diff: Option<StateDiff>,
+    /// Trace
+    pub trace: Vec<Trace>,
+    /// VM trace
+    #[serde(rename = "vmTrace")]
+    pub vm_trace: Option<VMTrace>,
+    /// Transaction hash
+    #[serde(rename = "transactionHash")]
+    pub transaction_hash: Option<H256>,
+}
+
+/// Trace filter
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceFilter {
+    #[serde(rename = "fromBlock", skip_serializing_if = "Option::is_none")]
+    from_block: Option<BlockNumber>,
+    #[serde(rename = "toBlock", skip_serializing_if = "Option::is_none")]
+    to_block: Option<BlockNumber>,
+    #[serde(rename = "fromAddress", skip_serializing_if = "Option::is_none")]
+    from_address: Option<Vec<Address>>,
+    #[serde(rename = "toAddress", skip_serializing_if = "Option::is_none")]
+    to_address: Option<Vec<Address>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    after: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    count: Option<usize>,
+}
+
+/// Build a trace filter
+#[derive(Debug, Default, Clone)]
+pub struct TraceFilterBuilder {
+    filter: TraceFilter,
+}
+
+impl TraceFilterBuilder {
+    /// Filters traces from this block.
+    pub fn from_block(mut self, block: BlockNumber) -> Self {
+        self.filter.from_block = Some(block);
+        self
+    }
+
+    /// Filters traces to this block.
+    pub fn to_block(mut self, block: BlockNumber) -> Self {
+        self.filter.to_block = Some(block);
+        self
+    }
+
+    /// Filters traces from these addresses.
+    pub fn from_address(mut self, addresses: Vec<Address>) -> Self {
+        self.filter.from_address = Some(addresses);
+        self
+    }
+
+    /// Filters traces to these addresses.
+    pub fn to_address(mut self, addresses: Vec<Address>) -> Self {
+        self.filter.to_address = Some(addresses);
+        self
+    }
+
+    /// Sets the offset into the matched set of traces to start returning results from.
+    pub fn after(mut self, after: usize) -> Self {
+        self.filter.after = Some(after);
+        self
+    }
+
+    /// Sets the maximum number of traces to return.
+    pub fn count(mut self, count: usize) -> Self {
+        self.filter.count = Some(count);
+        self
+    }
+
+    /// Build the filter
+    pub fn build(&self) -> TraceFilter {
+        self.filter.clone()
+    }
+}